@@ -1,4 +1,5 @@
 use crossterm::style::{Attribute, Color};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::style::CellAlignment;
 
@@ -16,23 +17,188 @@ pub struct Cell {
     pub(crate) fg: Option<Color>,
     pub(crate) bg: Option<Color>,
     pub(crate) attributes: Vec<Attribute>,
+    /// The number of consecutive columns this cell occupies. Defaults to `1`.\
+    /// See [Cell::arrange_span_width] for how this is reserved/summed across columns.
+    pub(crate) span: usize,
+    /// The cached display width of the cell's content, i.e. the max
+    /// [unicode width](UnicodeWidthStr::width) over all content lines.\
+    /// This is precomputed so the column-width arrangement logic doesn't have to rescan the
+    /// content on every pass.
+    pub(crate) display_width: usize,
 }
 
 impl Cell {
     /// Create a new Cell
     pub fn new<T: ToString>(content: T) -> Self {
+        let content: Vec<String> = content
+            .to_string()
+            .split('\n')
+            .map(|content| content.to_string())
+            .collect();
+        let display_width = Self::measure_display_width(&content);
+
         Cell {
-            content: content
-                .to_string()
-                .split('\n')
-                .map(|content| content.to_string())
-                .collect(),
+            content,
             delimiter: None,
             alignment: None,
             fg: None,
             bg: None,
             attributes: Vec::new(),
+            span: 1,
+            display_width,
+        }
+    }
+
+    /// Measure the display width of a cell's content, i.e. the maximum
+    /// [unicode width](UnicodeWidthStr::width) over all of its lines.\
+    /// Wide glyphs (e.g. CJK characters) count as `2`. ANSI/style attributes aren't part of the
+    /// printable content and don't affect the result.
+    fn measure_display_width(content: &[String]) -> usize {
+        content
+            .iter()
+            .map(|line| UnicodeWidthStr::width(line.as_str()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the cached display width of this cell's content.\
+    /// This is cheaper than measuring the content yourself, since it's precomputed whenever
+    /// the cell's content changes.
+    pub fn get_display_width(&self) -> usize {
+        self.display_width
+    }
+
+    /// Let this cell span over several columns.
+    ///
+    /// This is intended for e.g. a centered title row that stretches across the whole table.
+    /// The combined content width for a spanning cell is computed by
+    /// [Cell::arrange_span_width]; alignment is then applied across that combined width rather
+    /// than a single column's width.
+    /// ```
+    /// use comfy_table::{Cell, CellAlignment};
+    ///
+    /// let cell = Cell::new("Summary")
+    ///     .set_span(3)
+    ///     .set_alignment(CellAlignment::Center);
+    /// ```
+    pub fn set_span(mut self, span: usize) -> Self {
+        self.span = span;
+
+        self
+    }
+
+    /// Return the number of columns this cell spans.
+    pub fn get_span(&self) -> usize {
+        self.span
+    }
+
+    /// Compute the combined content width this cell occupies when arranged into a row of
+    /// columns, given the base (single-column) width of each column.
+    ///
+    /// `start_column` is the zero-based index of the column this cell starts in and
+    /// `delimiter_width` is the width of the vertical delimiter rendered between two adjacent
+    /// columns. The cell's own [span](Cell::get_span) is reserved and summed across that many
+    /// consecutive columns, plus the delimiters between them; a span that runs past the last
+    /// available column is clamped down to however many columns are actually available.
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::new("Summary").set_span(3);
+    /// let column_widths = [5, 5, 5, 5];
+    ///
+    /// // Reserves columns 0..=2 (5 + 5 + 5) plus the two delimiters between them.
+    /// assert_eq!(cell.arrange_span_width(&column_widths, 0, 1), 17);
+    /// // A span running past the last column clamps to the 2 columns that are left.
+    /// assert_eq!(cell.arrange_span_width(&column_widths, 2, 1), 11);
+    /// ```
+    pub fn arrange_span_width(
+        &self,
+        column_widths: &[usize],
+        start_column: usize,
+        delimiter_width: usize,
+    ) -> usize {
+        if start_column >= column_widths.len() {
+            return 0;
+        }
+
+        let available_columns = column_widths.len() - start_column;
+        let span = self.span.max(1).min(available_columns);
+
+        let reserved_width: usize = column_widths[start_column..start_column + span].iter().sum();
+        let delimiter_width = delimiter_width * span.saturating_sub(1);
+
+        reserved_width + delimiter_width
+    }
+
+    /// Wrap this cell's content so each rendered line fits within `width` display columns,
+    /// splitting on this cell's [delimiter](Cell::set_delimiter) (` ` by default).\
+    /// Words are never split: a word wider than `width` is kept whole on its own line, which
+    /// then exceeds `width`. A `width` of `0` leaves every line unwrapped.
+    ///
+    /// Pass the single column's width for an unspanned cell, or the combined width returned by
+    /// [Cell::arrange_span_width] for a spanning cell — wrapping only cares about how many
+    /// columns it's given to fill, not how many columns that width is made up of.
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::new("one two three");
+    /// assert_eq!(cell.wrap_content(7), vec!["one two", "three"]);
+    /// ```
+    pub fn wrap_content(&self, width: usize) -> Vec<String> {
+        let delimiter = self.delimiter.unwrap_or(' ');
+
+        self.content
+            .iter()
+            .flat_map(|line| Self::wrap_line(line, delimiter, width))
+            .collect()
+    }
+
+    /// Wrap a single line of text on `delimiter` so each resulting line fits within `width`
+    /// display columns. A word wider than `width` stays whole on its own line; a `width` of `0`
+    /// returns the line unchanged.
+    fn wrap_line(line: &str, delimiter: char, width: usize) -> Vec<String> {
+        if width == 0 || UnicodeWidthStr::width(line) <= width {
+            return vec![line.to_string()];
+        }
+
+        let delimiter_width = UnicodeWidthChar::width(delimiter).unwrap_or(0);
+        let mut wrapped = Vec::new();
+        let mut current = String::new();
+        let mut current_width = 0;
+
+        for word in line.split(delimiter) {
+            let word_width = UnicodeWidthStr::width(word);
+            let separator_width = if current.is_empty() { 0 } else { delimiter_width };
+
+            if current_width + separator_width + word_width > width && !current.is_empty() {
+                wrapped.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+
+            if !current.is_empty() {
+                current.push(delimiter);
+                current_width += delimiter_width;
+            }
+
+            current.push_str(word);
+            current_width += word_width;
+        }
+
+        if !current.is_empty() {
+            wrapped.push(current);
         }
+
+        wrapped
+    }
+
+    /// Create a new [Cell] with a given alignment, in a single step.
+    /// ```
+    /// use comfy_table::{Cell, CellAlignment};
+    ///
+    /// let cell = Cell::new_align("13.37", CellAlignment::Right);
+    /// ```
+    pub fn new_align<T: ToString>(content: T, alignment: CellAlignment) -> Self {
+        Cell::new(content).set_alignment(alignment)
     }
 
     /// Return a copy of the content contained in this cell.
@@ -124,8 +290,126 @@ impl Cell {
 
         self
     }
+
+    /// Build a [Cell] from a compact style-spec string, instead of chaining builder calls.
+    ///
+    /// The spec is scanned left to right. `F<c>` sets the foreground and `B<c>` sets the
+    /// background, where `<c>` is a one-letter color code (`r`=Red, `g`=Green, `b`=Blue,
+    /// `y`=Yellow, `w`=White, `d`=Black, `c`=Cyan, `m`=Magenta). `H<n>` sets a horizontal span
+    /// of `n` columns. The remaining codes are bare flags: `b`/`i`/`u` add the Bold/Italic/
+    /// Underlined attribute, and `l`/`c`/`r` set Left/Center/Right alignment. Any other
+    /// character is rejected with [SpecParseError::UnknownCode].
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::from_style_spec("x", "Frbc").unwrap();
+    /// ```
+    pub fn from_style_spec<T: ToString>(content: T, spec: &str) -> Result<Cell, SpecParseError> {
+        let mut cell = Cell::new(content);
+        let mut chars = spec.chars().peekable();
+
+        while let Some(code) = chars.next() {
+            match code {
+                'F' | 'B' => {
+                    let color_code = chars
+                        .next()
+                        .ok_or(SpecParseError::UnexpectedEnd(code))?;
+                    let color = Cell::color_from_code(color_code)
+                        .ok_or(SpecParseError::UnknownColor(color_code))?;
+
+                    cell = if code == 'F' { cell.fg(color) } else { cell.bg(color) };
+                }
+                'H' => {
+                    let mut digits = String::new();
+                    while let Some(&next) = chars.peek() {
+                        if next.is_ascii_digit() {
+                            digits.push(next);
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+
+                    if digits.is_empty() {
+                        return Err(SpecParseError::UnexpectedEnd('H'));
+                    }
+
+                    let span: usize = digits
+                        .parse()
+                        .map_err(|_| SpecParseError::InvalidSpan(digits.clone()))?;
+
+                    if span == 0 {
+                        return Err(SpecParseError::InvalidSpan(digits));
+                    }
+
+                    cell = cell.set_span(span);
+                }
+                'b' => cell = cell.add_attribute(Attribute::Bold),
+                'i' => cell = cell.add_attribute(Attribute::Italic),
+                'u' => cell = cell.add_attribute(Attribute::Underlined),
+                'l' => cell = cell.set_alignment(CellAlignment::Left),
+                'c' => cell = cell.set_alignment(CellAlignment::Center),
+                'r' => cell = cell.set_alignment(CellAlignment::Right),
+                other => return Err(SpecParseError::UnknownCode(other)),
+            }
+        }
+
+        Ok(cell)
+    }
+
+    /// Map a single-letter style-spec color code to its [Color].
+    fn color_from_code(code: char) -> Option<Color> {
+        match code {
+            'r' => Some(Color::Red),
+            'g' => Some(Color::Green),
+            'b' => Some(Color::Blue),
+            'y' => Some(Color::Yellow),
+            'w' => Some(Color::White),
+            'd' => Some(Color::Black),
+            'c' => Some(Color::Cyan),
+            'm' => Some(Color::Magenta),
+            _ => None,
+        }
+    }
+}
+
+/// An error that occurs while parsing a compact style-spec string via
+/// [Cell::from_style_spec].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpecParseError {
+    /// A code that requires an argument (`F`, `B` or `H`) was the last character in the spec,
+    /// or `H` was not followed by a digit.
+    UnexpectedEnd(char),
+    /// `F`/`B` was followed by a character that isn't a known color code.
+    UnknownColor(char),
+    /// `H` was followed by digits that don't fit into a `usize`, or by `0` (a cell must span
+    /// at least one column).
+    InvalidSpan(String),
+    /// A character that isn't a recognized code or flag.
+    UnknownCode(char),
+}
+
+impl std::fmt::Display for SpecParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpecParseError::UnexpectedEnd(code) => {
+                write!(f, "style-spec code '{code}' is missing its argument")
+            }
+            SpecParseError::UnknownColor(code) => {
+                write!(f, "'{code}' is not a known style-spec color code")
+            }
+            SpecParseError::InvalidSpan(digits) => {
+                write!(f, "'{digits}' is not a valid span in a style-spec")
+            }
+            SpecParseError::UnknownCode(code) => {
+                write!(f, "'{code}' is not a known style-spec code")
+            }
+        }
+    }
 }
 
+impl std::error::Error for SpecParseError {}
+
 impl<T: ToString> From<T> for Cell {
     /// Convert to a new [Cell].
     ///
@@ -175,6 +459,31 @@ impl ToCell for Cell {
     }
 }
 
+/// Allow the conversion of a type to a vector of cells that all share the same alignment.
+///
+/// This is the alignment-aware counterpart of [ToCells], useful when building a whole row
+/// of e.g. right-aligned numeric cells from a `Vec<i32>`, without wrapping each element in
+/// `Cell::new(x).set_alignment(a)` by hand.
+/// ```
+/// use comfy_table::{CellAlignment, ToCellsWithAlignment};
+///
+/// let cells = vec![1, 2, 3].to_cells_with_alignment(CellAlignment::Right);
+/// ```
+pub trait ToCellsWithAlignment {
+    fn to_cells_with_alignment(self, alignment: CellAlignment) -> Vec<Cell>;
+}
+
+impl<T: IntoIterator> ToCellsWithAlignment for T
+where
+    T::Item: ToCell,
+{
+    fn to_cells_with_alignment(self, alignment: CellAlignment) -> Vec<Cell> {
+        self.into_iter()
+            .map(|item| item.to_cell().set_alignment(alignment))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,4 +495,209 @@ mod tests {
 
         assert_eq!(cell.get_content(), content);
     }
+
+    #[test]
+    fn test_span_defaults_to_one() {
+        let cell = Cell::new("content");
+
+        assert_eq!(cell.get_span(), 1);
+    }
+
+    #[test]
+    fn test_set_span_stores_value() {
+        let cell = Cell::new("content").set_span(3);
+
+        assert_eq!(cell.get_span(), 3);
+    }
+
+    #[test]
+    fn test_arrange_span_width_reserves_and_sums_columns() {
+        let cell = Cell::new("Summary").set_span(3);
+        let column_widths = [5, 5, 5, 5];
+
+        assert_eq!(cell.arrange_span_width(&column_widths, 0, 1), 5 + 1 + 5 + 1 + 5);
+    }
+
+    #[test]
+    fn test_arrange_span_width_clamps_past_last_column() {
+        let cell = Cell::new("Summary").set_span(10);
+        let column_widths = [5, 5, 5, 5];
+
+        assert_eq!(cell.arrange_span_width(&column_widths, 2, 1), 5 + 1 + 5);
+    }
+
+    #[test]
+    fn test_arrange_span_width_unspanned_cell_uses_single_column() {
+        let cell = Cell::new("x");
+        let column_widths = [5, 5, 5];
+
+        assert_eq!(cell.arrange_span_width(&column_widths, 1, 1), 5);
+    }
+
+    #[test]
+    fn test_arrange_span_width_start_past_last_column_is_zero() {
+        let cell = Cell::new("x");
+        let column_widths = [5, 5];
+
+        assert_eq!(cell.arrange_span_width(&column_widths, 2, 1), 0);
+    }
+
+    #[test]
+    fn test_wrap_content_fits_within_width() {
+        let cell = Cell::new("short");
+
+        assert_eq!(cell.wrap_content(10), vec!["short".to_string()]);
+    }
+
+    #[test]
+    fn test_wrap_content_wraps_on_delimiter() {
+        let cell = Cell::new("one two three");
+
+        assert_eq!(
+            cell.wrap_content(7),
+            vec!["one two".to_string(), "three".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_wrap_content_against_combined_span_width() {
+        let cell = Cell::new("one two three four").set_span(2);
+        let column_widths = [4, 4, 4];
+        let width = cell.arrange_span_width(&column_widths, 0, 1);
+
+        // A single column (width 4) could only ever fit one word per line; the combined
+        // span width (4 + 1 + 4 = 9) lets two words share a line instead.
+        assert_eq!(width, 9);
+        assert_eq!(
+            cell.wrap_content(width),
+            vec!["one two".to_string(), "three".to_string(), "four".to_string()]
+        );
+        assert_eq!(
+            cell.wrap_content(column_widths[0]),
+            vec![
+                "one".to_string(),
+                "two".to_string(),
+                "three".to_string(),
+                "four".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrap_content_accounts_for_wide_delimiter_width() {
+        // A full-width space (`\u{3000}`) has a display width of 2, not 1.
+        let cell = Cell::new("one\u{3000}two\u{3000}three").set_delimiter('\u{3000}');
+
+        let wrapped = cell.wrap_content(7);
+
+        for line in &wrapped {
+            assert!(UnicodeWidthStr::width(line.as_str()) <= 7);
+        }
+        assert_eq!(
+            wrapped,
+            vec!["one".to_string(), "two".to_string(), "three".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_display_width_ascii() {
+        let cell = Cell::new("foo");
+
+        assert_eq!(cell.get_display_width(), 3);
+    }
+
+    #[test]
+    fn test_display_width_multiline_takes_longest_line() {
+        let cell = Cell::new("a\nbbbbb\ncc");
+
+        assert_eq!(cell.get_display_width(), 5);
+    }
+
+    #[test]
+    fn test_display_width_counts_wide_cjk_glyphs_as_two() {
+        let cell = Cell::new("中文");
+
+        assert_eq!(cell.get_display_width(), 4);
+    }
+
+    #[test]
+    fn test_from_style_spec_doc_example_parses() {
+        let cell = Cell::from_style_spec("x", "Frbc").unwrap();
+
+        assert_eq!(cell.fg, Some(Color::Red));
+        assert_eq!(cell.attributes, vec![Attribute::Bold]);
+        assert_eq!(cell.alignment, Some(CellAlignment::Center));
+    }
+
+    #[test]
+    fn test_from_style_spec_foreground_background_and_span() {
+        let cell = Cell::from_style_spec("x", "FgBwH2").unwrap();
+
+        assert_eq!(cell.fg, Some(Color::Green));
+        assert_eq!(cell.bg, Some(Color::White));
+        assert_eq!(cell.get_span(), 2);
+    }
+
+    #[test]
+    fn test_from_style_spec_attributes_and_alignment() {
+        let cell = Cell::from_style_spec("x", "biul").unwrap();
+
+        assert_eq!(
+            cell.attributes,
+            vec![Attribute::Bold, Attribute::Italic, Attribute::Underlined]
+        );
+        assert_eq!(cell.alignment, Some(CellAlignment::Left));
+    }
+
+    #[test]
+    fn test_from_style_spec_missing_color_argument() {
+        let err = Cell::from_style_spec("x", "F").unwrap_err();
+
+        assert_eq!(err, SpecParseError::UnexpectedEnd('F'));
+    }
+
+    #[test]
+    fn test_from_style_spec_unknown_color() {
+        let err = Cell::from_style_spec("x", "Fz").unwrap_err();
+
+        assert_eq!(err, SpecParseError::UnknownColor('z'));
+    }
+
+    #[test]
+    fn test_from_style_spec_missing_span_digits() {
+        let err = Cell::from_style_spec("x", "H").unwrap_err();
+
+        assert_eq!(err, SpecParseError::UnexpectedEnd('H'));
+    }
+
+    #[test]
+    fn test_from_style_spec_rejects_zero_span() {
+        let err = Cell::from_style_spec("x", "H0").unwrap_err();
+
+        assert_eq!(err, SpecParseError::InvalidSpan("0".to_string()));
+    }
+
+    #[test]
+    fn test_from_style_spec_unknown_code() {
+        let err = Cell::from_style_spec("x", "q").unwrap_err();
+
+        assert_eq!(err, SpecParseError::UnknownCode('q'));
+    }
+
+    #[test]
+    fn test_new_align_sets_alignment() {
+        let cell = Cell::new_align("13.37", CellAlignment::Right);
+
+        assert_eq!(cell.alignment, Some(CellAlignment::Right));
+    }
+
+    #[test]
+    fn test_to_cells_with_alignment_applies_to_every_cell() {
+        let cells = vec![1, 2, 3].to_cells_with_alignment(CellAlignment::Right);
+
+        assert_eq!(cells.len(), 3);
+        for cell in cells {
+            assert_eq!(cell.alignment, Some(CellAlignment::Right));
+        }
+    }
 }